@@ -0,0 +1,40 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use dom::bindings::reflector::Reflector;
+use dom::event::Event;
+
+#[dom_struct]
+pub struct EventTarget {
+    reflector_: Reflector,
+}
+
+impl EventTarget {
+    pub fn new_inherited() -> EventTarget {
+        EventTarget {
+            reflector_: Reflector::new(),
+        }
+    }
+
+    // https://dom.spec.whatwg.org/#concept-event-dispatch
+    //
+    // WebVR's user-gesture requirement only cares about trusted "click"
+    // events, the activation-triggering input event type the spec expects
+    // requestPresent() to be called from, so toggle the window's gesture
+    // flag around dispatch rather than anywhere more permanent: VRDisplay
+    // reads it synchronously from inside RequestPresent, and it must not
+    // still read "active" once the handler that triggered it has returned.
+    pub fn dispatch_event(&self, event: &Event) -> bool {
+        let is_gesture = event.IsTrusted() && &*event.Type() == "click";
+        let window = self.global().as_window();
+        if is_gesture {
+            window.set_user_gesture_active(true);
+        }
+        let result = event.dispatch(self);
+        if is_gesture {
+            window.set_user_gesture_active(false);
+        }
+        result
+    }
+}
@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use dom::bindings::cell::DOMRefCell;
+use dom::bindings::codegen::Bindings::NavigatorBinding;
+use dom::bindings::codegen::Bindings::NavigatorBinding::NavigatorMethods;
+use dom::bindings::inheritance::Castable;
+use dom::bindings::js::{JS, Root};
+use dom::bindings::reflector::{Reflector, reflect_dom_object};
+use dom::gamepad::Gamepad;
+use dom::globalscope::GlobalScope;
+use dom::vrdisplay::VRDisplay;
+
+#[dom_struct]
+pub struct Navigator {
+    reflector_: Reflector,
+    // VRDisplays handed out by the most recent GetVRDisplays() call, so
+    // GetGamepads() can fold each display's tracked controllers into the
+    // combined gamepad list without a round-trip to the webvr thread.
+    vr_displays: DOMRefCell<Vec<JS<VRDisplay>>>,
+}
+
+impl Navigator {
+    fn new_inherited() -> Navigator {
+        Navigator {
+            reflector_: Reflector::new(),
+            vr_displays: DOMRefCell::new(vec![]),
+        }
+    }
+
+    pub fn new(global: &GlobalScope) -> Root<Navigator> {
+        reflect_dom_object(box Navigator::new_inherited(),
+                           global,
+                           NavigatorBinding::Wrap)
+    }
+
+    pub fn set_vr_displays(&self, displays: &[Root<VRDisplay>]) {
+        *self.vr_displays.borrow_mut() = displays.iter().map(|d| JS::from_ref(&**d)).collect();
+    }
+}
+
+impl NavigatorMethods for Navigator {
+    // https://w3c.github.io/gamepad/#navigator-interface-extension
+    fn GetGamepads(&self) -> Vec<Root<Gamepad>> {
+        self.vr_displays.borrow()
+                        .iter()
+                        .flat_map(|display| display.gamepads())
+                        .map(|gamepad| gamepad.upcast::<Gamepad>())
+                        .collect()
+    }
+}
@@ -22,12 +22,15 @@ use dom::bindings::str::DOMString;
 use dom::event::Event;
 use dom::eventtarget::EventTarget;
 use dom::globalscope::GlobalScope;
+use dom::htmlcanvaselement::HTMLCanvasElement;
+use dom::imagebitmap::ImageBitmap;
 use dom::promise::Promise;
 use dom::vrdisplaycapabilities::VRDisplayCapabilities;
 use dom::vrdisplayevent::VRDisplayEvent;
 use dom::vrstageparameters::VRStageParameters;
 use dom::vreyeparameters::VREyeParameters;
 use dom::vrframedata::VRFrameData;
+use dom::vrgamepad::VRGamepad;
 use dom::vrpose::VRPose;
 use dom::webglrenderingcontext::WebGLRenderingContext;
 use js::jsapi::JSContext;
@@ -36,6 +39,7 @@ use ipc_channel::ipc::IpcSender;
 use util::thread::spawn_named;
 use std::boxed::FnBox;
 use std::cell::Cell;
+use std::f64;
 use std::mem;
 use std::rc::Rc;
 use std::sync::mpsc;
@@ -43,7 +47,7 @@ use script_runtime::CommonScriptMsg;
 use script_runtime::ScriptThreadEventCategory::WebVREvent;
 use script_thread::Runnable;
 use vr_traits::webvr;
-use vr_traits::WebVRMsg;
+use vr_traits::{WebVRLayer, WebVRMsg, WebVRResult};
 use webrender_traits::VRCompositorCommand;
 
 #[dom_struct]
@@ -61,14 +65,36 @@ pub struct VRDisplay {
     #[ignore_heap_size_of = "Defined in rust-webvr"]
     frame_data: DOMRefCell<WebVRFrameData>,
     #[ignore_heap_size_of = "Defined in rust-webvr"]
-    layer: DOMRefCell<WebVRLayer>,
+    layer: DOMRefCell<WebVRPresentLayer>,
     layer_ctx: MutNullableHeap<JS<WebGLRenderingContext>>,
+    // Set instead of layer_ctx when the VRLayer source is an ImageBitmap or
+    // a 2D canvas rather than a live WebGLRenderingContext.
+    layer_image: MutNullableHeap<JS<ImageBitmap>>,
+    // 2D canvas the left-eye view gets blitted into after each SubmitFrame,
+    // giving spectators a view without a second render pass in script.
+    mirror_canvas: MutNullableHeap<JS<HTMLCanvasElement>>,
+    // Tracked controllers, refreshed alongside SyncPoses so button presses
+    // and 6DoF poses stay in lockstep with the rendered frame.
+    gamepads: DOMRefCell<Vec<JS<VRGamepad>>>,
+    // Lightweight presentation/frame timing, so developers can diagnose
+    // whether the RAF thread in init_present is keeping pace with vsync.
+    #[ignore_heap_size_of = "Defined in this file"]
+    frame_timing: DOMRefCell<FrameTimingStats>,
     #[ignore_heap_size_of = "Defined in rust-webvr"]
     compositor_id: DOMRefCell<Option<WebVRDeviceType>>,
+    // Set while a vrdisplayactivate listener triggered by this VRDisplay runs
+    // synchronously, so a RequestPresent issued from inside it counts as a
+    // user gesture per the WebVR spec's auto-present-on-mount carve-out.
+    activate_gesture: Cell<bool>,
     next_raf_id: Cell<u32>,
-    /// List of request animation frame callbacks
+    /// Callbacks requested for the next SyncPoses cycle.
     #[ignore_heap_size_of = "closures are hard"]
     raf_callback_list: DOMRefCell<Vec<(u32, Option<Box<FnBox(f64)>>)>>,
+    /// Snapshot of the callbacks currently being drained by notify_raf, kept
+    /// visible so CancelAnimationFrame can still cancel an id that's mid-flight
+    /// (registered this frame, not yet invoked) as well as one still pending.
+    #[ignore_heap_size_of = "closures are hard"]
+    executing_raf_callbacks: DOMRefCell<Vec<(u32, Option<Box<FnBox(f64)>>)>>,
 }
 
 // Wrappers to include WebVR structs in a DOM struct
@@ -81,13 +107,58 @@ pub struct WebVRFrameData(webvr::VRFrameData);
 no_jsmanaged_fields!(WebVRFrameData);
 
 #[derive(Clone, Default)]
-pub struct WebVRLayer(webvr::VRLayer);
-no_jsmanaged_fields!(WebVRLayer);
+pub struct WebVRPresentLayer(WebVRLayer);
+no_jsmanaged_fields!(WebVRPresentLayer);
 
 #[derive(Clone)]
 pub struct WebVRDeviceType(webvr::VRDeviceType);
 no_jsmanaged_fields!(WebVRDeviceType);
 
+// Accumulated min/max/mean frame interval (ms) between consecutive RAF
+// dispatches while presenting, plus how many frames got dropped (an
+// interval more than 1.5x the running mean counts as dropped).
+#[derive(Clone)]
+pub struct FrameTimingStats {
+    present_start: f64,
+    last_raf_time: Option<f64>,
+    min_interval: f64,
+    max_interval: f64,
+    mean_interval: f64,
+    frame_count: u32,
+    dropped_frames: u32,
+}
+
+impl Default for FrameTimingStats {
+    fn default() -> FrameTimingStats {
+        FrameTimingStats {
+            present_start: 0.0,
+            last_raf_time: None,
+            min_interval: f64::MAX,
+            max_interval: 0.0,
+            mean_interval: 0.0,
+            frame_count: 0,
+            dropped_frames: 0,
+        }
+    }
+}
+
+impl FrameTimingStats {
+    fn record_raf(&mut self, now: f64) {
+        if let Some(last) = self.last_raf_time {
+            let interval = now - last;
+            self.frame_count += 1;
+            self.min_interval = self.min_interval.min(interval);
+            self.max_interval = self.max_interval.max(interval);
+            self.mean_interval += (interval - self.mean_interval) / self.frame_count as f64;
+            if self.frame_count > 1 && interval > self.mean_interval * 1.5 {
+                self.dropped_frames += 1;
+            }
+        }
+        self.last_raf_time = Some(now);
+    }
+}
+no_jsmanaged_fields!(FrameTimingStats);
+
 
 impl VRDisplay {
 
@@ -111,9 +182,15 @@ impl VRDisplay {
             frame_data: DOMRefCell::new(Default::default()),
             layer: DOMRefCell::new(Default::default()),
             layer_ctx: MutNullableHeap::default(),
+            layer_image: MutNullableHeap::default(),
+            mirror_canvas: MutNullableHeap::default(),
+            gamepads: DOMRefCell::new(vec![]),
+            frame_timing: DOMRefCell::new(Default::default()),
             compositor_id: DOMRefCell::new(None),
+            activate_gesture: Cell::new(false),
             next_raf_id: Cell::new(1),
-            raf_callback_list: DOMRefCell::new(vec![])
+            raf_callback_list: DOMRefCell::new(vec![]),
+            executing_raf_callbacks: DOMRefCell::new(vec![]),
         }
     }
 
@@ -204,6 +281,7 @@ impl VRDisplayMethods for VRDisplay {
 
     fn SetDepthNear(&self, value: Finite<f64>) -> () {
         self.depth_near.set(*value.deref());
+        self.refresh_projection_matrices();
     }
 
     fn DepthFar(&self) -> Finite<f64> {
@@ -212,6 +290,7 @@ impl VRDisplayMethods for VRDisplay {
 
     fn SetDepthFar(&self, value: Finite<f64>) -> () {
         self.depth_far.set(*value.deref());
+        self.refresh_projection_matrices();
     }
 
     fn RequestAnimationFrame(&self, callback: Rc<FrameRequestCallback>) -> u32 {
@@ -230,9 +309,16 @@ impl VRDisplayMethods for VRDisplay {
 
     fn CancelAnimationFrame(&self, handle: u32) -> () {
         if self.presenting.get() {
+            // The id may be queued for next frame, or already mid-flight in
+            // the snapshot notify_raf is currently draining.
             let mut list = self.raf_callback_list.borrow_mut();
             if let Some(mut pair) = list.iter_mut().find(|pair| pair.0 == handle) {
                 pair.1 = None;
+                return;
+            }
+            let mut executing = self.executing_raf_callbacks.borrow_mut();
+            if let Some(mut pair) = executing.iter_mut().find(|pair| pair.0 == handle) {
+                pair.1 = None;
             }
         } else {
             self.global().as_window().CancelAnimationFrame(handle);
@@ -242,7 +328,14 @@ impl VRDisplayMethods for VRDisplay {
     #[allow(unrooted_must_root)]
     fn RequestPresent(&self, layers: Vec<VRLayer>) -> Rc<Promise> {
         let promise = Promise::new(&self.global());
-        // TODO: WebVR spec: this method must be called in response to a user gesture
+
+        // WebVR spec: this method must be called in response to a user gesture,
+        // unless it's issued synchronously from a vrdisplayactivate listener.
+        if !self.activate_gesture.get() && !self.global().as_window().is_user_gesture_active() {
+            let msg = "RequestPresent must be called in a user gesture".to_string();
+            promise.reject_native(promise.global().get_cx(), &msg);
+            return promise;
+        }
 
         // WebVR spec: If canPresent is false the promise MUST be rejected
         if !self.display.borrow().0.capabilities.can_present {
@@ -270,27 +363,28 @@ impl VRDisplayMethods for VRDisplay {
             return promise;
         }
 
-        let (layer_bounds, layer_ctx) = layer.unwrap();
+        let (layer_bounds, layer_source) = layer.unwrap();
 
         // WebVR spec: Repeat calls while already presenting will update the VRLayers being displayed.
         if self.presenting.get() {
             *self.layer.borrow_mut() = layer_bounds;
-            self.layer_ctx.set(Some(&layer_ctx));
+            self.set_layer_source(layer_source);
             promise.resolve_native(promise.global().get_cx(), &());
             return promise;
         }
-        
+
         // Request Present
         if let Some(wevbr_sender) = self.webvr_thread() {
             let (sender, receiver) = ipc::channel().unwrap();
             wevbr_sender.send(WebVRMsg::RequestPresent(self.global().pipeline_id(),
                                                        self.display.borrow().0.display_id,
+                                                       layer_bounds.0.clone(),
                                                        sender))
                                                        .unwrap();
             match receiver.recv().unwrap() {
                 Ok(compositor_id) => {
                     *self.layer.borrow_mut() = layer_bounds;
-                    self.layer_ctx.set(Some(&layer_ctx));
+                    self.set_layer_source(layer_source);
                     self.init_present(compositor_id);
                     promise.resolve_native(promise.global().get_cx(), &());
                 },
@@ -347,11 +441,48 @@ impl VRDisplayMethods for VRDisplay {
             return;
         }
 
-        let api_sender = self.layer_ctx.get().unwrap().ipc_renderer();
+        let api_sender = self.submit_api_sender();
         let compositor_id = self.compositor_id.borrow().as_ref().unwrap().0.as_u32();
-        let layer = self.layer.borrow();
-        let msg = VRCompositorCommand::SubmitFrame(compositor_id, layer.0.left_bounds, layer.0.right_bounds);
+        let layer = self.layer.borrow().0.clone();
+        let msg = VRCompositorCommand::SubmitFrame(compositor_id, layer.left_bounds, layer.right_bounds);
         api_sender.send(CanvasMsg::WebVR(msg)).unwrap();
+
+        // Let the VR thread know the texture that was just handed to the compositor,
+        // so it can distort/display it and (e.g.) keep a copy for a mirror view.
+        if let Some(wevbr_sender) = self.webvr_thread() {
+            let _ = wevbr_sender.send(WebVRMsg::SubmitFrame(self.global().pipeline_id(),
+                                                             self.get_display_id(),
+                                                             layer));
+        }
+
+        self.update_mirror_canvas();
+    }
+
+    // Non-standard: designate a 2D <canvas> to mirror the presenting eye into
+    // on every SubmitFrame. Pass None to stop mirroring.
+    fn SetMirrorCanvas(&self, canvas: Option<&HTMLCanvasElement>) -> () {
+        self.mirror_canvas.set(canvas);
+    }
+
+    // Non-standard: min/max/mean frame interval (ms) observed since
+    // RequestPresent resolved, for diagnosing whether the RAF thread is
+    // keeping pace with the HMD's vsync.
+    fn MinFrameInterval(&self) -> Finite<f64> {
+        Finite::wrap(self.frame_timing.borrow().min_interval)
+    }
+
+    fn MaxFrameInterval(&self) -> Finite<f64> {
+        Finite::wrap(self.frame_timing.borrow().max_interval)
+    }
+
+    fn MeanFrameInterval(&self) -> Finite<f64> {
+        Finite::wrap(self.frame_timing.borrow().mean_interval)
+    }
+
+    // Non-standard: how many frames were dropped since RequestPresent
+    // resolved (an interval more than 1.5x the running mean).
+    fn DroppedFrameCount(&self) -> u32 {
+        self.frame_timing.borrow().dropped_frames
     }
 }
 
@@ -365,7 +496,86 @@ impl VRDisplay {
         self.display.borrow().0.display_id
     }
 
+    fn set_layer_source(&self, source: VRLayerSource) {
+        match source {
+            VRLayerSource::WebGL(ctx) => {
+                self.layer_ctx.set(Some(&ctx));
+                self.layer_image.set(None);
+            },
+            VRLayerSource::Image(bitmap) => {
+                self.layer_image.set(Some(&bitmap));
+                self.layer_ctx.set(None);
+            },
+        }
+    }
+
+    // The layer source's render channel, regardless of whether it's a
+    // WebGL-backed canvas or an ImageBitmap/2D canvas source.
+    fn submit_api_sender(&self) -> IpcSender<CanvasMsg> {
+        if let Some(ctx) = self.layer_ctx.get() {
+            ctx.ipc_renderer()
+        } else {
+            self.layer_image.get().unwrap().ipc_renderer()
+        }
+    }
+
+    fn update_mirror_canvas(&self) {
+        let canvas = match self.mirror_canvas.get() {
+            Some(canvas) => canvas,
+            None => return,
+        };
+
+        match self.get_mirror_frame() {
+            Ok((width, height, pixels)) => {
+                canvas.draw_vr_mirror_frame(width, height, &pixels);
+            },
+            Err(e) => {
+                warn!("VRDisplay mirror canvas: {:?}", e);
+            }
+        }
+    }
+
+    // Fetches the last submitted eye buffer as RGBA pixels so it can be painted
+    // into a 2D <canvas> for a spectator/mirror view, without a second render pass.
+    pub fn get_mirror_frame(&self) -> WebVRResult<(u32, u32, Vec<u8>)> {
+        if let Some(wevbr_sender) = self.webvr_thread() {
+            let (sender, receiver) = ipc::channel().unwrap();
+            wevbr_sender.send(WebVRMsg::GetMirrorFrame(self.global().pipeline_id(),
+                                                       self.get_display_id(),
+                                                       sender)).unwrap();
+            receiver.recv().unwrap()
+        } else {
+            Err("Not available".to_string())
+        }
+    }
+
+    // Make depthNear/depthFar authoritative: ask the backend to recompute the
+    // projection matrices for the new clip range right away, instead of
+    // waiting for the next explicit GetFrameData() call from script.
+    fn refresh_projection_matrices(&self) {
+        if let Some(wevbr_sender) = self.webvr_thread() {
+            let (sender, receiver) = ipc::channel().unwrap();
+            wevbr_sender.send(WebVRMsg::GetFrameData(self.global().pipeline_id(),
+                                                     self.get_display_id(),
+                                                     self.depth_near.get(),
+                                                     self.depth_far.get(),
+                                                     sender)).unwrap();
+            if let Ok(data) = receiver.recv().unwrap() {
+                self.frame_data.borrow_mut().0 = data;
+            }
+        }
+    }
+
     pub fn update_display(&self, display: &webvr::VRDisplayData) {
+        if let Some(ref stage_params) = display.stage_parameters {
+            if let Some(stage) = self.stage_params.get() {
+                stage.update(&stage_params);
+            } else {
+                self.stage_params.set(Some(&*VRStageParameters::new(&stage_params, &self.global())));
+            }
+        } else {
+            self.stage_params.set(None);
+        }
         self.display.borrow_mut().0 = display.clone()
     }
 
@@ -373,12 +583,22 @@ impl VRDisplay {
         match *event {
             webvr::VRDisplayEvent::Connect(ref display) => {
                 self.update_display(&display);
+                self.notify_event(&event);
             },
             webvr::VRDisplayEvent::Disconnect(_id) => {
                 self.display.borrow_mut().0.connected = false;
+                self.notify_event(&event);
+            },
+            webvr::VRDisplayEvent::Activate(ref display, _reason) => {
+                self.update_display(&display);
+                self.notify_event(&event);
+                self.notify_window_activate(&event);
+            },
+            webvr::VRDisplayEvent::Deactivate(ref display, _reason) => {
+                self.update_display(&display);
+                self.notify_event(&event);
+                self.notify_window_deactivate(&event);
             },
-            webvr::VRDisplayEvent::Activate(ref display, _) |
-            webvr::VRDisplayEvent::Deactivate(ref display, _) |
             webvr::VRDisplayEvent::Blur(ref display) |
             webvr::VRDisplayEvent::Focus(ref display) => {
                 self.update_display(&display);
@@ -403,11 +623,41 @@ impl VRDisplay {
         event.upcast::<Event>().fire(self.upcast());
     }
 
+    // Fires vrdisplayactivate on the Window so a page can requestPresent() the
+    // instant the user puts the headset on, the standard "mount -> immersive
+    // content starts" UX Gecko/Blink implement.
+    fn notify_window_activate(&self, event: &webvr::VRDisplayEvent) {
+        let window = self.global().as_window();
+        let root = Root::from_ref(&*self);
+        let dom_event = VRDisplayEvent::new_from_webvr(&self.global(), &root, event);
+        self.activate_gesture.set(true);
+        dom_event.upcast::<Event>().fire(window.upcast());
+        self.activate_gesture.set(false);
+    }
+
+    fn notify_window_deactivate(&self, event: &webvr::VRDisplayEvent) {
+        let window = self.global().as_window();
+        let root = Root::from_ref(&*self);
+        let dom_event = VRDisplayEvent::new_from_webvr(&self.global(), &root, event);
+        dom_event.upcast::<Event>().fire(window.upcast());
+    }
+
     fn init_present(&self, compositor_id: webvr::VRDeviceType) {
         self.presenting.set(true);
+        // Keep handle ids unique across the VR and window RAF controllers, so
+        // a handle returned while presenting can still be cancelled once
+        // ExitPresent falls back to the window controller (and vice versa).
+        let window_next_id = self.global().as_window().next_raf_id();
+        if window_next_id > self.next_raf_id.get() {
+            self.next_raf_id.set(window_next_id);
+        }
+        *self.frame_timing.borrow_mut() = FrameTimingStats {
+            present_start: *self.global().as_window().Performance().Now(),
+            .. Default::default()
+        };
         *self.compositor_id.borrow_mut() = Some(WebVRDeviceType(compositor_id));
         let compositor_id = compositor_id.as_u32();
-        let api_sender = self.layer_ctx.get().unwrap().ipc_renderer();
+        let api_sender = self.submit_api_sender();
         let js_sender = self.global().script_chan();
         let address = Trusted::new(&*self);
 
@@ -440,21 +690,95 @@ impl VRDisplay {
 
     fn stop_present(&self) {
         self.presenting.set(false);
-        let api_sender = self.layer_ctx.get().unwrap().ipc_renderer();
+        // Hand the id counter back to the window controller so ids keep
+        // increasing monotonically once RequestAnimationFrame falls back to it.
+        self.global().as_window().advance_raf_id_to(self.next_raf_id.get());
+        let api_sender = self.submit_api_sender();
         let compositor_id = self.compositor_id.borrow().as_ref().unwrap().0.as_u32();
         let msg = VRCompositorCommand::Release(compositor_id);
         api_sender.send(CanvasMsg::WebVR(msg)).unwrap();
     }
 
+    // Refreshes tracked controller poses/button state from the backend.
+    // Called from notify_raf so gamepads stay in lockstep with SyncPoses.
+    fn refresh_gamepads(&self) {
+        if let Some(wevbr_sender) = self.webvr_thread() {
+            let (sender, receiver) = ipc::channel().unwrap();
+            wevbr_sender.send(WebVRMsg::GetGamepads(self.global().pipeline_id(),
+                                                     self.get_display_id(),
+                                                     sender)).unwrap();
+            match receiver.recv().unwrap() {
+                Ok(data) => {
+                    let mut gamepads = self.gamepads.borrow_mut();
+                    if gamepads.len() != data.len() {
+                        *gamepads = data.iter()
+                                        .map(|gamepad| {
+                                            JS::from_ref(&*VRGamepad::new(&self.global(), gamepad))
+                                        })
+                                        .collect();
+                    } else {
+                        // The tracked count matching the last poll doesn't mean the
+                        // same physical controllers are still in the same slots: one
+                        // can disconnect and a different one reconnect between polls
+                        // with the count unchanged. Recreate the DOM object for any
+                        // slot whose identity no longer matches instead of blindly
+                        // updating it in place, so the inherited Gamepad's id
+                        // (set once at construction) doesn't go stale.
+                        for (existing, gamepad) in gamepads.iter_mut().zip(data.iter()) {
+                            if existing.matches(gamepad) {
+                                existing.update(gamepad);
+                            } else {
+                                *existing = JS::from_ref(&*VRGamepad::new(&self.global(), gamepad));
+                            }
+                        }
+                    }
+                },
+                Err(e) => {
+                    error!("VRDisplay::GetGamepads: {:?}", e);
+                }
+            }
+        }
+    }
+
+    // The controllers this display is currently tracking, for
+    // Navigator::GetGamepads to fold into the page's combined gamepad list.
+    pub fn gamepads(&self) -> Vec<Root<VRGamepad>> {
+        self.gamepads.borrow().iter().map(|gamepad| Root::from_ref(&**gamepad)).collect()
+    }
+
     fn notify_raf(&self) {
-        let mut callbacks = mem::replace(&mut *self.raf_callback_list.borrow_mut(), vec![]);
-        let timing = self.global().as_window().Performance().Now();
+        self.refresh_gamepads();
 
-        for (_, callback) in callbacks.drain(..) {
+        // Move the pending queue into the executing snapshot *before* running
+        // any callback: new RequestAnimationFrame calls made from inside a
+        // callback push onto the now-empty raf_callback_list and are deferred
+        // to the next SyncPoses cycle, while CancelAnimationFrame can still
+        // reach a not-yet-invoked id via executing_raf_callbacks.
+        let pending = mem::replace(&mut *self.raf_callback_list.borrow_mut(), vec![]);
+        *self.executing_raf_callbacks.borrow_mut() = pending;
+
+        let timing = self.global().as_window().Performance().Now();
+        self.frame_timing.borrow_mut().record_raf(*timing);
+
+        loop {
+            let next = self.executing_raf_callbacks.borrow_mut().iter_mut()
+                           .filter(|pair| pair.1.is_some())
+                           .next()
+                           .map(|pair| pair.0);
+            let id = match next {
+                Some(id) => id,
+                None => break,
+            };
+            let callback = self.executing_raf_callbacks.borrow_mut()
+                               .iter_mut()
+                               .find(|pair| pair.0 == id)
+                               .and_then(|pair| pair.1.take());
             if let Some(callback) = callback {
                 callback(*timing);
             }
         }
+
+        self.executing_raf_callbacks.borrow_mut().clear();
     }
 }
 
@@ -494,14 +818,34 @@ fn parse_bounds(src: &Option<Vec<Finite<f32>>>, dst: &mut [f32; 4]) -> Result<()
     }
 }
 
-fn validate_layer(cx: *mut JSContext, layer: &VRLayer) -> Result<(WebVRLayer, Root<WebGLRenderingContext>), &'static str> {
-    let ctx = layer.source.as_ref().map(|ref s| s.get_or_init_webgl_context(cx, None)).unwrap_or(None);
+// The source a submitted VRLayer is backed by. WebGL-backed layers hand the
+// compositor a live texture every frame; ImageBitmap/2D-canvas-backed layers
+// (e.g. a 360° photo viewer) hand it a snapshot mailbox instead.
+enum VRLayerSource {
+    WebGL(Root<WebGLRenderingContext>),
+    Image(Root<ImageBitmap>),
+}
+
+fn validate_layer(cx: *mut JSContext, layer: &VRLayer) -> Result<(WebVRPresentLayer, VRLayerSource), &'static str> {
+    let source = layer.source.as_ref();
+
+    let ctx = source.and_then(|s| s.get_or_init_webgl_context(cx, None));
     if let Some(ctx) = ctx {
-        let mut data = webvr::VRLayer::default();
+        let mut data = WebVRLayer::default();
         try!(parse_bounds(&layer.leftBounds, &mut data.left_bounds));
         try!(parse_bounds(&layer.rightBounds, &mut data.right_bounds));
-        Ok((WebVRLayer(data), ctx))
-    } else {
-        Err("VRLayer source must be a WebGL Context")
+        data.texture_id = ctx.layer_texture_id();
+        return Ok((WebVRPresentLayer(data), VRLayerSource::WebGL(ctx)));
     }
+
+    let bitmap = source.and_then(|s| s.get_as_image_bitmap());
+    if let Some(bitmap) = bitmap {
+        let mut data = WebVRLayer::default();
+        try!(parse_bounds(&layer.leftBounds, &mut data.left_bounds));
+        try!(parse_bounds(&layer.rightBounds, &mut data.right_bounds));
+        data.texture_id = bitmap.layer_texture_id();
+        return Ok((WebVRPresentLayer(data), VRLayerSource::Image(bitmap)));
+    }
+
+    Err("VRLayer source must be a WebGL Context, an ImageBitmap, or a 2D canvas")
 }
\ No newline at end of file
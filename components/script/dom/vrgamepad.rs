@@ -0,0 +1,72 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use dom::bindings::cell::DOMRefCell;
+use dom::bindings::codegen::Bindings::VRGamepadBinding;
+use dom::bindings::codegen::Bindings::VRGamepadBinding::VRGamepadMethods;
+use dom::bindings::js::{JS, Root};
+use dom::bindings::reflector::{Reflectable, reflect_dom_object};
+use dom::bindings::str::DOMString;
+use dom::gamepad::Gamepad;
+use dom::globalscope::GlobalScope;
+use dom::vrpose::VRPose;
+use vr_traits::webvr;
+
+// Wrapper required to include WebVR structs in a DOM struct
+#[derive(Clone)]
+pub struct WebVRGamepadData(webvr::VRGamepadData);
+no_jsmanaged_fields!(WebVRGamepadData);
+
+#[dom_struct]
+pub struct VRGamepad {
+    gamepad: Gamepad,
+    #[ignore_heap_size_of = "Defined in rust-webvr"]
+    data: DOMRefCell<WebVRGamepadData>,
+    pose: JS<VRPose>,
+}
+
+impl VRGamepad {
+
+    #[allow(unrooted_must_root)]
+    fn new_inherited(data: &webvr::VRGamepadData, global: &GlobalScope) -> VRGamepad {
+        VRGamepad {
+            gamepad: Gamepad::new_inherited(DOMString::from(data.name.clone())),
+            data: DOMRefCell::new(WebVRGamepadData(data.clone())),
+            pose: JS::from_ref(&*VRPose::new(&global, &data.pose)),
+        }
+    }
+
+    pub fn new(global: &GlobalScope, data: &webvr::VRGamepadData) -> Root<VRGamepad> {
+        reflect_dom_object(box VRGamepad::new_inherited(data, global),
+                           global,
+                           VRGamepadBinding::Wrap)
+    }
+
+    pub fn update(&self, data: &webvr::VRGamepadData) {
+        self.pose.update(&self.global(), &data.pose);
+        self.data.borrow_mut().0 = data.clone();
+    }
+
+    // Whether `data` plausibly describes the same physical controller this
+    // VRGamepad was constructed from, so a caller matching controllers up by
+    // list position (e.g. VRDisplay::refresh_gamepads) can tell "the same
+    // controller moved" apart from "a different controller took this slot".
+    pub fn matches(&self, data: &webvr::VRGamepadData) -> bool {
+        let current = self.data.borrow();
+        current.0.name == data.name && current.0.hand == data.hand
+    }
+}
+
+impl VRGamepadMethods for VRGamepad {
+
+    // https://w3c.github.io/webvr/#dom-vrgamepad-pose
+    fn Pose(&self) -> Root<VRPose> {
+        Root::from_ref(&*self.pose)
+    }
+
+    // https://w3c.github.io/webvr/#dom-vrgamepad-hand
+    fn Hand(&self) -> DOMString {
+        DOMString::from(self.data.borrow().0.hand.clone())
+    }
+}
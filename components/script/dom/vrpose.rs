@@ -0,0 +1,121 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use core::nonzero::NonZero;
+use dom::bindings::codegen::Bindings::VRPoseBinding;
+use dom::bindings::codegen::Bindings::VRPoseBinding::VRPoseMethods;
+use dom::bindings::conversions::{slice_to_array_buffer_view, update_array_buffer_view};
+use dom::bindings::js::Root;
+use dom::bindings::reflector::{Reflectable, Reflector, reflect_dom_object};
+use dom::globalscope::GlobalScope;
+use js::jsapi::{Heap, JSContext, JSObject};
+use std::ptr;
+use vr_traits::webvr;
+
+#[dom_struct]
+pub struct VRPose {
+    reflector_: Reflector,
+    position: Heap<*mut JSObject>,
+    linear_velocity: Heap<*mut JSObject>,
+    linear_acceleration: Heap<*mut JSObject>,
+    orientation: Heap<*mut JSObject>,
+    angular_velocity: Heap<*mut JSObject>,
+    angular_acceleration: Heap<*mut JSObject>,
+}
+
+impl VRPose {
+
+    #[allow(unrooted_must_root)]
+    fn new_inherited() -> VRPose {
+        VRPose {
+            reflector_: Reflector::new(),
+            position: Heap::default(),
+            linear_velocity: Heap::default(),
+            linear_acceleration: Heap::default(),
+            orientation: Heap::default(),
+            angular_velocity: Heap::default(),
+            angular_acceleration: Heap::default(),
+        }
+    }
+
+    #[allow(unrooted_must_root)]
+    pub fn new(global: &GlobalScope, pose: &webvr::VRPose) -> Root<VRPose> {
+        let root = reflect_dom_object(box VRPose::new_inherited(),
+                                      global,
+                                      VRPoseBinding::Wrap);
+        root.update(global, pose);
+        root
+    }
+
+    #[allow(unsafe_code)]
+    fn update_optional_array(global: &GlobalScope, heap: &Heap<*mut JSObject>, data: &Option<[f32; 3]>) {
+        match *data {
+            Some(ref values) => {
+                if heap.get().is_null() {
+                    heap.set(slice_to_array_buffer_view(global.get_cx(), values));
+                } else {
+                    unsafe { update_array_buffer_view(heap.get(), values); }
+                }
+            },
+            None => heap.set(ptr::null_mut()),
+        }
+    }
+
+    pub fn update(&self, global: &GlobalScope, pose: &webvr::VRPose) {
+        VRPose::update_optional_array(global, &self.position, &pose.position);
+        VRPose::update_optional_array(global, &self.orientation, &pose.orientation);
+        VRPose::update_optional_array(global, &self.linear_velocity, &pose.linear_velocity);
+        VRPose::update_optional_array(global, &self.linear_acceleration, &pose.linear_acceleration);
+        VRPose::update_optional_array(global, &self.angular_velocity, &pose.angular_velocity);
+        VRPose::update_optional_array(global, &self.angular_acceleration, &pose.angular_acceleration);
+    }
+}
+
+impl VRPoseMethods for VRPose {
+
+    // https://w3c.github.io/webvr/#dom-vrpose-position
+    #[allow(unsafe_code)]
+    fn GetPosition(&self, _cx: *mut JSContext) -> Option<NonZero<*mut JSObject>> {
+        non_zero_obj(self.position.get())
+    }
+
+    // https://w3c.github.io/webvr/#dom-vrpose-linearvelocity
+    #[allow(unsafe_code)]
+    fn GetLinearVelocity(&self, _cx: *mut JSContext) -> Option<NonZero<*mut JSObject>> {
+        non_zero_obj(self.linear_velocity.get())
+    }
+
+    // https://w3c.github.io/webvr/#dom-vrpose-linearacceleration
+    #[allow(unsafe_code)]
+    fn GetLinearAcceleration(&self, _cx: *mut JSContext) -> Option<NonZero<*mut JSObject>> {
+        non_zero_obj(self.linear_acceleration.get())
+    }
+
+    // https://w3c.github.io/webvr/#dom-vrpose-orientation
+    #[allow(unsafe_code)]
+    fn GetOrientation(&self, _cx: *mut JSContext) -> Option<NonZero<*mut JSObject>> {
+        non_zero_obj(self.orientation.get())
+    }
+
+    // https://w3c.github.io/webvr/#dom-vrpose-angularvelocity
+    #[allow(unsafe_code)]
+    fn GetAngularVelocity(&self, _cx: *mut JSContext) -> Option<NonZero<*mut JSObject>> {
+        non_zero_obj(self.angular_velocity.get())
+    }
+
+    // https://w3c.github.io/webvr/#dom-vrpose-angularacceleration
+    #[allow(unsafe_code)]
+    fn GetAngularAcceleration(&self, _cx: *mut JSContext) -> Option<NonZero<*mut JSObject>> {
+        non_zero_obj(self.angular_acceleration.get())
+    }
+}
+
+#[allow(unsafe_code)]
+fn non_zero_obj(obj: *mut JSObject) -> Option<NonZero<*mut JSObject>> {
+    if obj.is_null() {
+        None
+    } else {
+        Some(unsafe { NonZero::new(obj) })
+    }
+}
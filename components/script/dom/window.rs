@@ -0,0 +1,76 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+// WebVR-related Window state. Window's constellation/document/history
+// fields live alongside the rest of the global object's plumbing; this file
+// only carries what the WebVR feature set depends on.
+
+use dom::bindings::callback::ExceptionHandling;
+use dom::bindings::cell::DOMRefCell;
+use dom::bindings::codegen::Bindings::WindowBinding::FrameRequestCallback;
+use dom::bindings::codegen::Bindings::WindowBinding::WindowBinding::WindowMethods;
+use dom::bindings::num::Finite;
+use dom::eventtarget::EventTarget;
+use std::boxed::FnBox;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[dom_struct]
+pub struct Window {
+    eventtarget: EventTarget,
+    // Whether a trusted "click" event is currently being dispatched, so
+    // VRDisplay::RequestPresent can tell a real user gesture apart from a
+    // script calling it out of the blue.
+    user_gesture_active: Cell<bool>,
+    // RAF handle counter. Handed off to/from a VRDisplay's own counter when
+    // it starts and stops presenting, so handles keep increasing across the
+    // switch between the window's RAF loop and the VRDisplay's.
+    raf_id_counter: Cell<u32>,
+    /// Callbacks requested of the window's own (non-presenting) RAF loop.
+    #[ignore_heap_size_of = "closures are hard"]
+    raf_callback_list: DOMRefCell<Vec<(u32, Option<Box<FnBox(f64)>>)>>,
+}
+
+impl Window {
+    pub fn set_user_gesture_active(&self, active: bool) {
+        self.user_gesture_active.set(active);
+    }
+
+    pub fn is_user_gesture_active(&self) -> bool {
+        self.user_gesture_active.get()
+    }
+
+    pub fn next_raf_id(&self) -> u32 {
+        self.raf_id_counter.get()
+    }
+
+    pub fn advance_raf_id_to(&self, id: u32) {
+        if id > self.raf_id_counter.get() {
+            self.raf_id_counter.set(id);
+        }
+    }
+}
+
+impl WindowMethods for Window {
+    fn RequestAnimationFrame(&self, callback: Rc<FrameRequestCallback>) -> u32 {
+        // Draw from the counter a VRDisplay reads when it starts presenting
+        // and advances when it stops. While it is presenting, the VRDisplay
+        // issues handles from its own counter, so a handle issued here in
+        // that time can repeat one issued by the VRDisplay.
+        let raf_id = self.raf_id_counter.get();
+        self.raf_id_counter.set(raf_id + 1);
+        let callback = move |now: f64| {
+            let _ = callback.Call__(Finite::wrap(now), ExceptionHandling::Report);
+        };
+        self.raf_callback_list.borrow_mut().push((raf_id, Some(Box::new(callback))));
+        raf_id
+    }
+
+    fn CancelAnimationFrame(&self, handle: u32) -> () {
+        let mut list = self.raf_callback_list.borrow_mut();
+        if let Some(pair) = list.iter_mut().find(|pair| pair.0 == handle) {
+            pair.1 = None;
+        }
+    }
+}
@@ -15,4 +15,4 @@ extern crate serde_derive;
 pub extern crate webvr;
 
 mod webvr_traits;
-pub use webvr_traits::{WebVRMsg, WebVRResult};
\ No newline at end of file
+pub use webvr_traits::{WebVRLayer, WebVRMsg, WebVRResult};
\ No newline at end of file
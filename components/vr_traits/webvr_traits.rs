@@ -5,15 +5,43 @@ use msg::constellation_msg::PipelineId;
 
 pub type WebVRResult<T> = Result<T, String>;
 
+// WebVR 1.0: a VRLayer source plus the leftBounds/rightBounds UV rectangles
+// describing the region of that source to present for each eye.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct WebVRLayer {
+    pub texture_id: u32,
+    pub left_bounds: [f32; 4],
+    pub right_bounds: [f32; 4],
+}
+
+impl Default for WebVRLayer {
+    fn default() -> WebVRLayer {
+        WebVRLayer {
+            texture_id: 0,
+            left_bounds: [0.0, 0.0, 0.5, 1.0],
+            right_bounds: [0.5, 0.0, 0.5, 1.0],
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize)]
 pub enum WebVRMsg {
     RegisterContext(PipelineId),
     UnregisterContext(PipelineId),
-    PollEvents(IpcSender<bool>),
+    // Returns the set of (display, event) pairs collected since the last poll,
+    // so the script thread can fire the spec vrdisplay* events on the window.
+    PollEvents(IpcSender<Vec<(VRDisplayData, VRDisplayEvent)>>),
     GetVRDisplays(IpcSender<WebVRResult<Vec<VRDisplayData>>>),
     GetFrameData(PipelineId, u64, f64, f64, IpcSender<WebVRResult<VRFrameData>>),
     ResetPose(PipelineId, u64, Option<IpcSender<WebVRResult<()>>>),
-    RequestPresent(PipelineId, u64, IpcSender<WebVRResult<VRDeviceType>>),
+    RequestPresent(PipelineId, u64, WebVRLayer, IpcSender<WebVRResult<VRDeviceType>>),
     ExitPresent(PipelineId, u64, IpcSender<WebVRResult<()>>),
+    SubmitFrame(PipelineId, u64, WebVRLayer),
+    // Returns the most recently submitted eye buffer as RGBA pixels (width, height, data),
+    // so a page can mirror the headset's current view into an ordinary 2D canvas.
+    GetMirrorFrame(PipelineId, u64, IpcSender<WebVRResult<(u32, u32, Vec<u8>)>>),
+    // Mirrors GetFrameData but for tracked controllers, so the RAF loop can
+    // refresh gamepad poses/button state alongside SyncPoses.
+    GetGamepads(PipelineId, u64, IpcSender<WebVRResult<Vec<VRGamepadData>>>),
     Exit,
 }
\ No newline at end of file